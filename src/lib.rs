@@ -1,19 +1,50 @@
-use base64::{STANDARD, URL_SAFE};
-use base64_serde::base64_serde_type;
+use base64::{
+    read::DecoderReader,
+    write::EncoderWriter,
+    {STANDARD, URL_SAFE},
+};
 use bytes::Bytes;
 use core::{
     convert::TryFrom,
+    fmt,
     ops::{Deref, DerefMut},
     str::FromStr,
 };
+use std::io::{self, Read, Write};
 #[cfg(test)]
 use proptest_derive::Arbitrary;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Dispatches to the `Deserializer` method matching how `Serialize` encoded the value (see the
+/// `is_human_readable` branch there): `deserialize_any` for human-readable formats, which are
+/// self-describing and happily accept the string, byte, or sequence representations the
+/// `Visitor` implements; `deserialize_bytes` for binary formats, since non-self-describing ones
+/// like bincode only support the `Deserializer` method matching what was actually written and
+/// error out of `deserialize_any` entirely.
+fn deserialize_base64_like<'de, D, V>(deserializer: D, visitor: V) -> Result<V::Value, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Visitor<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
 
-// use official base64_serde crate to generate a type with correct serde implementations.
-base64_serde_type!(Base64StandardSerde, STANDARD);
 /// Base64 newtype wrapper using `STANDARD` encoding. May be generally treated as if it
 /// were a primitive Vec, e.g. `&Base64` will provide `&[u8]`.
+///
+/// Serialization is format-aware: human-readable formats (e.g. JSON) get a base64 string,
+/// while binary formats (e.g. CBOR, bincode) get the raw bytes directly, avoiding the ~33%
+/// base64 overhead. Deserialization accepts either representation, so a `Base64` round-trips
+/// losslessly through both kinds of format.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(test, derive(Arbitrary))]
 pub struct Base64(pub Vec<u8>);
@@ -36,7 +67,60 @@ impl Serialize for Base64 {
     where
         S: Serializer,
     {
-        Base64StandardSerde::serialize(&self.0, serializer)
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&base64::encode_config(&self.0, STANDARD))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+struct Base64Visitor;
+impl<'de> Visitor<'de> for Base64Visitor {
+    type Value = Base64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a base64 string, raw bytes, or a sequence of bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        base64::decode_config(v, STANDARD)
+            .map(Base64)
+            .map_err(E::custom)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Base64(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Base64(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(Base64(bytes))
     }
 }
 impl<'de> Deserialize<'de> for Base64 {
@@ -44,7 +128,7 @@ impl<'de> Deserialize<'de> for Base64 {
     where
         D: Deserializer<'de>,
     {
-        Base64StandardSerde::deserialize(deserializer).map(Base64)
+        deserialize_base64_like(deserializer, Base64Visitor)
     }
 }
 impl FromStr for Base64 {
@@ -53,6 +137,32 @@ impl FromStr for Base64 {
         base64::decode_config(s, STANDARD).map(Base64)
     }
 }
+impl Base64 {
+    /// Decodes `s` leniently: accepts either the standard or the URL-safe alphabet, and
+    /// accepts the input whether or not it carries `=` padding. Re-encoding the result (e.g.
+    /// via `to_string`) always produces this type's standard, padded alphabet, so strict
+    /// round-trip behavior is preserved for anything produced by this crate; forgiving
+    /// decoding is only used where the caller explicitly opts into it.
+    pub fn from_str_forgiving(s: &str) -> Result<Self, base64::DecodeError> {
+        decode_forgiving(s).map(Base64)
+    }
+
+    /// Streams the base64-encoded form of this value's bytes to `w` in fixed-size chunks,
+    /// without buffering the full encoded string. Useful for large payloads such as ciphertext
+    /// blobs.
+    pub fn encode_to_writer<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut encoder = EncoderWriter::new(w, STANDARD);
+        encoder.write_all(&self.0)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Streams base64-encoded bytes from `r` and decodes them in fixed-size chunks, without
+    /// buffering the full encoded string.
+    pub fn decode_from_reader<R: Read>(r: &mut R) -> Result<Self, ReadDecodeError> {
+        read_to_end_decoded(DecoderReader::new(r, STANDARD)).map(Base64)
+    }
+}
 impl From<&[u8]> for Base64 {
     fn from(value: &[u8]) -> Self {
         Base64(value.to_vec())
@@ -88,22 +198,251 @@ impl From<&Base64> for Bytes {
         Bytes::copy_from_slice(b64)
     }
 }
-impl TryFrom<Base64> for [u8; 32] {
-    type Error = String;
-    fn try_from(b64: Base64) -> Result<Self, Self::Error> {
-        if b64.len() == 32 {
-            let mut arr = [0u8; 32];
-            arr.copy_from_slice(&b64);
-            Ok(arr)
+/// The decoded base64 data did not have the expected fixed length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LengthError {
+    pub expected: usize,
+    pub actual: usize,
+}
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected {} bytes of base64-decoded data, but got {}",
+            self.expected, self.actual
+        )
+    }
+}
+impl std::error::Error for LengthError {}
+
+/// Decodes `s` accepting either base64 alphabet and with or without `=` padding, by
+/// normalizing the URL-safe characters to their standard equivalents and the padding to
+/// none before handing off to the `base64` crate.
+fn decode_forgiving(s: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    let canonical: String = s
+        .chars()
+        .map(|c| match c {
+            '-' => '+',
+            '_' => '/',
+            other => other,
+        })
+        .collect();
+    let config = base64::Config::new(base64::CharacterSet::Standard, false);
+    base64::decode_config(canonical.trim_end_matches('='), config)
+}
+
+/// Error produced by streaming decode from a reader: either the underlying `Read` failed (a
+/// broken pipe, a reset socket, a disk error), or the bytes it produced were not valid base64.
+#[derive(Debug)]
+pub enum ReadDecodeError {
+    Io(io::Error),
+    Decode(base64::DecodeError),
+}
+impl fmt::Display for ReadDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadDecodeError::Io(e) => write!(f, "{}", e),
+            ReadDecodeError::Decode(e) => write!(f, "{}", e),
+        }
+    }
+}
+impl std::error::Error for ReadDecodeError {}
+impl From<io::Error> for ReadDecodeError {
+    fn from(e: io::Error) -> Self {
+        ReadDecodeError::Io(e)
+    }
+}
+impl From<base64::DecodeError> for ReadDecodeError {
+    fn from(e: base64::DecodeError) -> Self {
+        ReadDecodeError::Decode(e)
+    }
+}
+
+/// Reads `r` to completion through `decoder`, surfacing invalid base64 as
+/// `ReadDecodeError::Decode` rather than a generic `io::Error`; a genuine I/O failure from the
+/// underlying reader is preserved as `ReadDecodeError::Io` rather than being relabeled as a
+/// decode error. `base64`'s `DecoderReader` reports decode failures as an `io::Error` wrapping
+/// the `DecodeError`, which is how the two are told apart here.
+fn read_to_end_decoded<R: Read>(mut decoder: DecoderReader<R>) -> Result<Vec<u8>, ReadDecodeError> {
+    let mut bytes = Vec::new();
+    if let Err(e) = decoder.read_to_end(&mut bytes) {
+        let is_decode_error = e
+            .get_ref()
+            .map_or(false, |inner| inner.is::<base64::DecodeError>());
+        return Err(if is_decode_error {
+            let decode_error = *e.into_inner().unwrap().downcast::<base64::DecodeError>().unwrap();
+            ReadDecodeError::Decode(decode_error)
         } else {
-            Err("Base64 was not 32 bytes of data.".to_string())
+            ReadDecodeError::Io(e)
+        });
+    }
+    Ok(bytes)
+}
+
+fn array_from_vec<const N: usize>(bytes: Vec<u8>) -> Result<[u8; N], LengthError> {
+    let actual = bytes.len();
+    <[u8; N]>::try_from(bytes).map_err(|_| LengthError { expected: N, actual })
+}
+
+/// Error produced when parsing or deserializing a [`Base64Array`] fails.
+#[derive(Debug)]
+pub enum Base64ArrayError {
+    Decode(base64::DecodeError),
+    Length(LengthError),
+}
+impl fmt::Display for Base64ArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base64ArrayError::Decode(e) => write!(f, "{}", e),
+            Base64ArrayError::Length(e) => write!(f, "{}", e),
+        }
+    }
+}
+impl std::error::Error for Base64ArrayError {}
+impl From<base64::DecodeError> for Base64ArrayError {
+    fn from(e: base64::DecodeError) -> Self {
+        Base64ArrayError::Decode(e)
+    }
+}
+impl From<LengthError> for Base64ArrayError {
+    fn from(e: LengthError) -> Self {
+        Base64ArrayError::Length(e)
+    }
+}
+
+/// Fixed-length base64 newtype wrapper using `STANDARD` encoding, e.g. `Base64Array<32>` for
+/// an AES key or `Base64Array<64>` for a signature. Unlike [`Base64`], the decoded byte length
+/// is checked against `N` at parse/deserialize time, so callers get a compile-time-sized,
+/// length-checked value instead of an ad-hoc runtime check.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Base64Array<const N: usize>(pub [u8; N]);
+impl<const N: usize> Deref for Base64Array<N> {
+    type Target = [u8; N];
+    fn deref(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+impl<const N: usize> DerefMut for Base64Array<N> {
+    fn deref_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+}
+impl<const N: usize> Serialize for Base64Array<N> {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&base64::encode_config(&self.0[..], STANDARD))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+struct Base64ArrayVisitor<const N: usize>;
+impl<'de, const N: usize> Visitor<'de> for Base64ArrayVisitor<N> {
+    type Value = Base64Array<N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a base64 string or {} bytes of raw data", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let bytes = base64::decode_config(v, STANDARD).map_err(E::custom)?;
+        let actual = bytes.len();
+        array_from_vec(bytes)
+            .map(Base64Array)
+            .map_err(|_| E::invalid_length(actual, &self))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let actual = v.len();
+        array_from_vec(v.to_vec())
+            .map(Base64Array)
+            .map_err(|_| E::invalid_length(actual, &self))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let actual = v.len();
+        array_from_vec(v)
+            .map(Base64Array)
+            .map_err(|_| E::invalid_length(actual, &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
         }
+        let actual = bytes.len();
+        array_from_vec(bytes)
+            .map(Base64Array)
+            .map_err(|_| de::Error::invalid_length(actual, &self))
+    }
+}
+impl<'de, const N: usize> Deserialize<'de> for Base64Array<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Base64Array<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_base64_like(deserializer, Base64ArrayVisitor::<N>)
+    }
+}
+impl<const N: usize> FromStr for Base64Array<N> {
+    type Err = Base64ArrayError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base64::decode_config(s, STANDARD)?;
+        Ok(Base64Array(array_from_vec(bytes)?))
+    }
+}
+impl<const N: usize> fmt::Display for Base64Array<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", base64::encode_config(&self.0[..], STANDARD))
+    }
+}
+impl<const N: usize> TryFrom<Base64> for Base64Array<N> {
+    type Error = LengthError;
+    fn try_from(b64: Base64) -> Result<Self, Self::Error> {
+        array_from_vec(b64.0).map(Base64Array)
+    }
+}
+impl<const N: usize> TryFrom<&[u8]> for Base64Array<N> {
+    type Error = LengthError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        array_from_vec(bytes.to_vec()).map(Base64Array)
+    }
+}
+impl<const N: usize> From<Base64Array<N>> for Base64 {
+    fn from(arr: Base64Array<N>) -> Self {
+        Base64(arr.0.to_vec())
     }
 }
 
-// use official base64_serde crate to generate a type with correct serde implementations.
-base64_serde_type!(UrlBase64Serde, URL_SAFE);
 /// Base64 newtype wrapper using `URL_SAFE` encoding. Used for Azure requests and responses.
+///
+/// Serialization is format-aware; see [`Base64`] for the human-readable-vs-binary behavior.
 #[derive(Debug, PartialEq, Eq, Default)]
 #[cfg_attr(test, derive(Arbitrary))]
 pub struct UrlBase64(pub Vec<u8>);
@@ -115,7 +454,60 @@ impl Serialize for UrlBase64 {
     where
         S: Serializer,
     {
-        UrlBase64Serde::serialize(&self.0, serializer)
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&base64::encode_config(&self.0, URL_SAFE))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+struct UrlBase64Visitor;
+impl<'de> Visitor<'de> for UrlBase64Visitor {
+    type Value = UrlBase64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a url-safe base64 string, raw bytes, or a sequence of bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        base64::decode_config(v, URL_SAFE)
+            .map(UrlBase64)
+            .map_err(E::custom)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(UrlBase64(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(UrlBase64(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(UrlBase64(bytes))
     }
 }
 impl<'de> Deserialize<'de> for UrlBase64 {
@@ -123,7 +515,30 @@ impl<'de> Deserialize<'de> for UrlBase64 {
     where
         D: Deserializer<'de>,
     {
-        UrlBase64Serde::deserialize(deserializer).map(UrlBase64)
+        deserialize_base64_like(deserializer, UrlBase64Visitor)
+    }
+}
+impl UrlBase64 {
+    /// Decodes `s` leniently: accepts either the standard or the URL-safe alphabet, and
+    /// accepts the input whether or not it carries `=` padding. See [`Base64::from_str_forgiving`]
+    /// for the full rationale.
+    pub fn from_str_forgiving(s: &str) -> Result<Self, base64::DecodeError> {
+        decode_forgiving(s).map(UrlBase64)
+    }
+
+    /// Streams the base64-encoded form of this value's bytes to `w` in fixed-size chunks. See
+    /// [`Base64::encode_to_writer`] for the rationale.
+    pub fn encode_to_writer<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut encoder = EncoderWriter::new(w, URL_SAFE);
+        encoder.write_all(&self.0)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Streams base64-encoded bytes from `r` and decodes them in fixed-size chunks. See
+    /// [`Base64::decode_from_reader`] for the rationale.
+    pub fn decode_from_reader<R: Read>(r: &mut R) -> Result<Self, ReadDecodeError> {
+        read_to_end_decoded(DecoderReader::new(r, URL_SAFE)).map(UrlBase64)
     }
 }
 impl From<&[u8]> for UrlBase64 {
@@ -147,11 +562,362 @@ impl From<Base64> for UrlBase64 {
     }
 }
 
+/// Base64 newtype wrapper for secret byte data (e.g. Azure key material, AES keys) using
+/// `STANDARD` encoding. Unlike [`Base64`], it zeroizes its buffer on drop, so key bytes don't
+/// linger in freed memory. In builds without `debug_assertions`, `Debug` output is redacted to
+/// the byte count; in builds with `debug_assertions`, `Debug` prints the base64 contents.
+/// Deliberately does not derive `Clone`, to avoid copies of the secret outliving the original.
+#[cfg(feature = "zeroize")]
+pub struct SecretBase64(pub Vec<u8>);
+#[cfg(feature = "zeroize")]
+impl Drop for SecretBase64 {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+#[cfg(feature = "zeroize")]
+impl PartialEq for SecretBase64 {
+    /// Constant-time comparison of the secret bytes, so comparing against attacker-influenced
+    /// input (e.g. a MAC) doesn't leak the secret through a timing side channel. The length
+    /// check short-circuits, but the length of key/MAC material is not itself secret.
+    fn eq(&self, other: &Self) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+        let mut diff = 0u8;
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+}
+#[cfg(feature = "zeroize")]
+impl Eq for SecretBase64 {}
+#[cfg(feature = "zeroize")]
+impl fmt::Debug for SecretBase64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if cfg!(debug_assertions) {
+            write!(
+                f,
+                "SecretBase64({})",
+                base64::encode_config(&self.0, STANDARD)
+            )
+        } else {
+            write!(f, "SecretBase64(<redacted, {} bytes>)", self.0.len())
+        }
+    }
+}
+#[cfg(feature = "zeroize")]
+impl Deref for SecretBase64 {
+    type Target = Vec<u8>;
+    fn deref(&self) -> &Vec<u8> {
+        &self.0
+    }
+}
+#[cfg(feature = "zeroize")]
+impl DerefMut for SecretBase64 {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.0
+    }
+}
+#[cfg(feature = "zeroize")]
+impl Serialize for SecretBase64 {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&base64::encode_config(&self.0, STANDARD))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+#[cfg(feature = "zeroize")]
+struct SecretBase64Visitor;
+#[cfg(feature = "zeroize")]
+impl<'de> Visitor<'de> for SecretBase64Visitor {
+    type Value = SecretBase64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a base64 string, raw bytes, or a sequence of bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        base64::decode_config(v, STANDARD)
+            .map(SecretBase64)
+            .map_err(E::custom)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(SecretBase64(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(SecretBase64(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(SecretBase64(bytes))
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<'de> Deserialize<'de> for SecretBase64 {
+    fn deserialize<D>(deserializer: D) -> Result<SecretBase64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_base64_like(deserializer, SecretBase64Visitor)
+    }
+}
+#[cfg(feature = "zeroize")]
+impl FromStr for SecretBase64 {
+    type Err = base64::DecodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        base64::decode_config(s, STANDARD).map(SecretBase64)
+    }
+}
+#[cfg(feature = "zeroize")]
+impl fmt::Display for SecretBase64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", base64::encode_config(&self.0, STANDARD))
+    }
+}
+
+/// Fixed-length variant of [`SecretBase64`], e.g. `SecretBase64Array<32>` for an AES key.
+/// See [`SecretBase64`] for the zeroize-on-drop and redacted-`Debug` behavior, and
+/// [`Base64Array`] for the length-checking behavior.
+#[cfg(feature = "zeroize")]
+pub struct SecretBase64Array<const N: usize>(pub [u8; N]);
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Drop for SecretBase64Array<N> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> PartialEq for SecretBase64Array<N> {
+    /// Constant-time comparison; see [`SecretBase64`]'s `PartialEq` impl for the rationale.
+    fn eq(&self, other: &Self) -> bool {
+        let mut diff = 0u8;
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Eq for SecretBase64Array<N> {}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> fmt::Debug for SecretBase64Array<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if cfg!(debug_assertions) {
+            write!(
+                f,
+                "SecretBase64Array({})",
+                base64::encode_config(&self.0[..], STANDARD)
+            )
+        } else {
+            write!(f, "SecretBase64Array(<redacted, {} bytes>)", N)
+        }
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Deref for SecretBase64Array<N> {
+    type Target = [u8; N];
+    fn deref(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> DerefMut for SecretBase64Array<N> {
+    fn deref_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Serialize for SecretBase64Array<N> {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&base64::encode_config(&self.0[..], STANDARD))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+#[cfg(feature = "zeroize")]
+struct SecretBase64ArrayVisitor<const N: usize>;
+#[cfg(feature = "zeroize")]
+impl<'de, const N: usize> Visitor<'de> for SecretBase64ArrayVisitor<N> {
+    type Value = SecretBase64Array<N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a base64 string or {} bytes of raw data", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let bytes = base64::decode_config(v, STANDARD).map_err(E::custom)?;
+        let actual = bytes.len();
+        array_from_vec(bytes)
+            .map(SecretBase64Array)
+            .map_err(|_| E::invalid_length(actual, &self))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let actual = v.len();
+        array_from_vec(v.to_vec())
+            .map(SecretBase64Array)
+            .map_err(|_| E::invalid_length(actual, &self))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let actual = v.len();
+        array_from_vec(v)
+            .map(SecretBase64Array)
+            .map_err(|_| E::invalid_length(actual, &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        let actual = bytes.len();
+        array_from_vec(bytes)
+            .map(SecretBase64Array)
+            .map_err(|_| de::Error::invalid_length(actual, &self))
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<'de, const N: usize> Deserialize<'de> for SecretBase64Array<N> {
+    fn deserialize<D>(deserializer: D) -> Result<SecretBase64Array<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_base64_like(deserializer, SecretBase64ArrayVisitor::<N>)
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> FromStr for SecretBase64Array<N> {
+    type Err = Base64ArrayError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base64::decode_config(s, STANDARD)?;
+        Ok(SecretBase64Array(array_from_vec(bytes)?))
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<const N: usize> fmt::Display for SecretBase64Array<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", base64::encode_config(&self.0[..], STANDARD))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use proptest::{array, collection, prelude::*};
 
+    #[test]
+    fn bincode_roundtrip_base64() {
+        let b64 = Base64(vec![2, 99, 0, 255]);
+        let bytes = bincode::serialize(&b64).unwrap();
+        let de: Base64 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(b64, de);
+    }
+
+    #[test]
+    fn bincode_roundtrip_url_base64() {
+        let b64 = UrlBase64(vec![2, 99, 0, 255]);
+        let bytes = bincode::serialize(&b64).unwrap();
+        let de: UrlBase64 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(b64, de);
+    }
+
+    #[test]
+    fn cbor_roundtrip_base64() {
+        let b64 = Base64(vec![2, 99, 0, 255]);
+        let bytes = serde_cbor::to_vec(&b64).unwrap();
+        let de: Base64 = serde_cbor::from_slice(&bytes).unwrap();
+        assert_eq!(b64, de);
+    }
+
+    #[test]
+    fn cbor_roundtrip_url_base64() {
+        let b64 = UrlBase64(vec![2, 99, 0, 255]);
+        let bytes = serde_cbor::to_vec(&b64).unwrap();
+        let de: UrlBase64 = serde_cbor::from_slice(&bytes).unwrap();
+        assert_eq!(b64, de);
+    }
+
+    struct BrokenReader;
+    impl std::io::Read for BrokenReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "pipe broken"))
+        }
+    }
+
+    #[test]
+    fn decode_from_reader_preserves_io_error() {
+        let received = Base64::decode_from_reader(&mut BrokenReader).unwrap_err();
+        match received {
+            ReadDecodeError::Io(e) => assert_eq!(e.kind(), io::ErrorKind::BrokenPipe),
+            ReadDecodeError::Decode(e) => {
+                panic!("expected a preserved io error, got a decode error instead: {}", e)
+            }
+        }
+    }
+
     mod url_base64 {
         use super::*;
 
@@ -179,6 +945,13 @@ mod tests {
             assert_eq!(se, r#""""#);
         }
 
+        #[test]
+        fn from_str_forgiving_accepts_stdbase64_and_missing_padding() {
+            let expected = UrlBase64(vec![0xff, 0xf8]);
+            let received = UrlBase64::from_str_forgiving("//g").unwrap();
+            assert_eq!(expected, received);
+        }
+
         proptest! {
             #[test]
             fn serde_roundtrip(b64 in any::<UrlBase64>()) {
@@ -187,6 +960,15 @@ mod tests {
                 assert_eq!(b64, de);
             }
 
+            #[test]
+            fn writer_reader_roundtrip(vec in collection::vec(any::<u8>(), 0..20)) {
+                let b64 = UrlBase64(vec);
+                let mut encoded = Vec::new();
+                b64.encode_to_writer(&mut encoded).unwrap();
+                let b64_post = UrlBase64::decode_from_reader(&mut &encoded[..]).unwrap();
+                assert_eq!(b64, b64_post);
+            }
+
             #[test]
             fn from_byte_slice(arr in array::uniform3(any::<u8>())) {
                 let slice = &arr[..];
@@ -234,6 +1016,13 @@ mod tests {
             assert!(received.is_data());
         }
 
+        #[test]
+        fn deserialize_from_numeric_seq() {
+            let expected = Base64(vec![2, 99]);
+            let received: Base64 = serde_json::from_str("[2, 99]").unwrap();
+            assert_eq!(expected, received);
+        }
+
         #[test]
         fn serialize_empty() {
             let se = serde_json::to_string(&Base64(Vec::new())).unwrap();
@@ -264,11 +1053,18 @@ mod tests {
             assert_eq!(expected, received);
         }
 
+        #[test]
+        fn from_str_forgiving_accepts_urlbase64_and_missing_padding() {
+            let expected = Base64(vec![0xff, 0xf8]);
+            let received = Base64::from_str_forgiving("__g").unwrap();
+            assert_eq!(expected, received);
+        }
+
         #[test]
         fn short_byte_slice_fail_from_b64() {
             // too short to work
             let b64 = Base64::from(&[0u8; 12][..]);
-            let received: Result<[u8; 32], _> = b64.try_into();
+            let received: Result<Base64Array<32>, _> = b64.try_into();
             assert!(received.is_err());
         }
 
@@ -276,7 +1072,7 @@ mod tests {
         fn long_byte_slice_fail_from_b64() {
             // too long to work
             let b64 = Base64::from(&[0u8; 64][..]);
-            let received: Result<[u8; 32], _> = b64.try_into();
+            let received: Result<Base64Array<32>, _> = b64.try_into();
             assert!(received.is_err());
         }
 
@@ -313,6 +1109,15 @@ mod tests {
                 assert_eq!(b64, b64_post);
             }
 
+            #[test]
+            fn writer_reader_roundtrip(vec in collection::vec(any::<u8>(), 0..20)) {
+                let b64 = Base64(vec);
+                let mut encoded = Vec::new();
+                b64.encode_to_writer(&mut encoded).unwrap();
+                let b64_post = Base64::decode_from_reader(&mut &encoded[..]).unwrap();
+                assert_eq!(b64, b64_post);
+            }
+
             #[test]
             fn to_bytes(vec in collection::vec(any::<u8>(), 0..20)) {
                 let expected = bytes::Bytes::from(vec.clone());
@@ -356,8 +1161,306 @@ mod tests {
             #[test]
             fn aes_key_try_from_b64(key in prop::array::uniform32(0u8..)) {
                 let b64 = Base64::from(&key[..]);
-                let received: [u8; 32] = b64.try_into().unwrap();
-                assert_eq!(key, received);
+                let received: Base64Array<32> = b64.try_into().unwrap();
+                assert_eq!(key, received.0);
+            }
+        }
+
+        mod base64_array {
+            use super::*;
+            use core::convert::TryInto;
+
+            #[test]
+            fn serde_roundtrip_known() {
+                let arr = Base64Array([2u8; 32]);
+                let ser = serde_json::to_string(&arr).unwrap();
+                let de: Base64Array<32> = serde_json::from_str(&ser).unwrap();
+                assert_eq!(arr, de);
+            }
+
+            #[test]
+            fn bincode_roundtrip() {
+                let arr = Base64Array([2u8; 32]);
+                let bytes = bincode::serialize(&arr).unwrap();
+                let de: Base64Array<32> = bincode::deserialize(&bytes).unwrap();
+                assert_eq!(arr, de);
+            }
+
+            #[test]
+            fn cbor_roundtrip() {
+                let arr = Base64Array([2u8; 32]);
+                let bytes = serde_cbor::to_vec(&arr).unwrap();
+                let de: Base64Array<32> = serde_cbor::from_slice(&bytes).unwrap();
+                assert_eq!(arr, de);
+            }
+
+            #[test]
+            fn deserialize_wrong_length_fails() {
+                let base64_str = base64::encode_config([0u8; 12], STANDARD);
+                let received = serde_json::from_str::<Base64Array<32>>(&format!(
+                    "\"{}\"",
+                    base64_str
+                ))
+                .unwrap_err();
+                assert!(received.is_data());
+            }
+
+            #[test]
+            fn from_str_wrong_length_fails() {
+                let base64_str = base64::encode_config([0u8; 12], STANDARD);
+                let received: Result<Base64Array<32>, _> = base64_str.parse();
+                assert!(received.is_err());
+            }
+
+            #[test]
+            fn str_roundtrip() {
+                let arr = Base64Array([7u8; 32]);
+                let received: Base64Array<32> = arr.to_string().parse().unwrap();
+                assert_eq!(arr, received);
+            }
+
+            #[test]
+            fn try_from_base64() {
+                let b64 = Base64::from(&[9u8; 64][..]);
+                let received: Base64Array<64> = b64.try_into().unwrap();
+                assert_eq!([9u8; 64], received.0);
+            }
+
+            #[test]
+            fn into_base64() {
+                let arr = Base64Array([3u8; 32]);
+                let received: Base64 = arr.into();
+                assert_eq!(Base64(vec![3u8; 32]), received);
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "zeroize"))]
+    mod secret_base64 {
+        use super::*;
+
+        #[test]
+        fn serde_roundtrip_known() {
+            let secret = SecretBase64(vec![2, 99]);
+            let ser = serde_json::to_string(&secret).unwrap();
+            let de: SecretBase64 = serde_json::from_str(&ser).unwrap();
+            assert_eq!(secret, de);
+        }
+
+        #[test]
+        fn str_roundtrip() {
+            let secret = SecretBase64(vec![2, 99]);
+            let received: SecretBase64 = secret.to_string().parse().unwrap();
+            assert_eq!(secret, received);
+        }
+
+        #[test]
+        fn debug_redacts_or_shows_contents_per_build() {
+            let secret = SecretBase64(vec![2, 99]);
+            let formatted = format!("{:?}", secret);
+            if cfg!(debug_assertions) {
+                assert_eq!(formatted, "SecretBase64(AmM=)");
+            } else {
+                assert_eq!(formatted, "SecretBase64(<redacted, 2 bytes>)");
+            }
+        }
+
+        #[test]
+        fn constant_time_eq() {
+            let a = SecretBase64(vec![1, 2, 3]);
+            let b = SecretBase64(vec![9, 2, 3]);
+            let c = SecretBase64(vec![1, 2, 3]);
+            assert_ne!(a, b);
+            assert_eq!(a, c);
+        }
+
+        #[test]
+        fn drop_zeroizes_buffer() {
+            // Reading through the dangling pointer after drop is a heuristic, not a guarantee,
+            // but it's the standard way zeroize-on-drop behavior gets exercised: the allocator
+            // doesn't touch freed memory on this path, so the zeroed bytes written by `drop`
+            // are still observable immediately afterward.
+            let mut secret = SecretBase64(vec![0xaa; 8]);
+            let ptr = secret.0.as_mut_ptr();
+            let len = secret.0.len();
+            drop(secret);
+            let after = unsafe { std::slice::from_raw_parts(ptr, len) };
+            assert!(after.iter().all(|&b| b == 0));
+        }
+
+        mod secret_base64_array {
+            use super::*;
+
+            #[test]
+            fn serde_roundtrip_known() {
+                let arr = SecretBase64Array([2u8; 32]);
+                let ser = serde_json::to_string(&arr).unwrap();
+                let de: SecretBase64Array<32> = serde_json::from_str(&ser).unwrap();
+                assert_eq!(arr, de);
+            }
+
+            #[test]
+            fn deserialize_wrong_length_fails() {
+                let base64_str = base64::encode_config([0u8; 12], STANDARD);
+                let received = serde_json::from_str::<SecretBase64Array<32>>(&format!(
+                    "\"{}\"",
+                    base64_str
+                ))
+                .unwrap_err();
+                assert!(received.is_data());
+            }
+
+            #[test]
+            fn from_str_wrong_length_fails() {
+                let base64_str = base64::encode_config([0u8; 12], STANDARD);
+                let received: Result<SecretBase64Array<32>, _> = base64_str.parse();
+                assert!(received.is_err());
+            }
+
+            #[test]
+            fn str_roundtrip() {
+                let arr = SecretBase64Array([7u8; 32]);
+                let received: SecretBase64Array<32> = arr.to_string().parse().unwrap();
+                assert_eq!(arr, received);
+            }
+
+            #[test]
+            fn constant_time_eq() {
+                let a = SecretBase64Array([1u8; 32]);
+                let mut other = [1u8; 32];
+                other[31] = 2;
+                let b = SecretBase64Array(other);
+                let c = SecretBase64Array([1u8; 32]);
+                assert_ne!(a, b);
+                assert_eq!(a, c);
+            }
+
+            #[test]
+            fn drop_zeroizes_buffer() {
+                // See secret_base64::drop_zeroizes_buffer for the caveat on this pattern. An
+                // inline array lives with the struct rather than behind a separate heap
+                // allocation, and dropping it in place through `ManuallyDrop` keeps that
+                // storage alive, so there's no dangling-pointer concern here.
+                let mut arr = core::mem::ManuallyDrop::new(SecretBase64Array([0xaa; 8]));
+                let ptr = arr.0.as_mut_ptr();
+                unsafe { core::mem::ManuallyDrop::drop(&mut arr) };
+                let after = unsafe { std::slice::from_raw_parts(ptr, 8) };
+                assert!(after.iter().all(|&b| b == 0));
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "zeroize"))]
+    mod secret_base64 {
+        use super::*;
+
+        #[test]
+        fn serde_roundtrip_known() {
+            let secret = SecretBase64(vec![2, 99]);
+            let ser = serde_json::to_string(&secret).unwrap();
+            let de: SecretBase64 = serde_json::from_str(&ser).unwrap();
+            assert_eq!(secret, de);
+        }
+
+        #[test]
+        fn str_roundtrip() {
+            let secret = SecretBase64(vec![2, 99]);
+            let received: SecretBase64 = secret.to_string().parse().unwrap();
+            assert_eq!(secret, received);
+        }
+
+        #[test]
+        fn debug_redacts_or_shows_contents_per_build() {
+            let secret = SecretBase64(vec![2, 99]);
+            let formatted = format!("{:?}", secret);
+            if cfg!(debug_assertions) {
+                assert_eq!(formatted, "SecretBase64(AmM=)");
+            } else {
+                assert_eq!(formatted, "SecretBase64(<redacted, 2 bytes>)");
+            }
+        }
+
+        #[test]
+        fn constant_time_eq() {
+            let a = SecretBase64(vec![1, 2, 3]);
+            let b = SecretBase64(vec![9, 2, 3]);
+            let c = SecretBase64(vec![1, 2, 3]);
+            assert_ne!(a, b);
+            assert_eq!(a, c);
+        }
+
+        #[test]
+        fn drop_zeroizes_buffer() {
+            // Reading through the dangling pointer after drop is a heuristic, not a guarantee,
+            // but it's the standard way zeroize-on-drop behavior gets exercised: the allocator
+            // doesn't touch freed memory on this path, so the zeroed bytes written by `drop`
+            // are still observable immediately afterward.
+            let mut secret = SecretBase64(vec![0xaa; 8]);
+            let ptr = secret.0.as_mut_ptr();
+            let len = secret.0.len();
+            drop(secret);
+            let after = unsafe { std::slice::from_raw_parts(ptr, len) };
+            assert!(after.iter().all(|&b| b == 0));
+        }
+
+        mod secret_base64_array {
+            use super::*;
+
+            #[test]
+            fn serde_roundtrip_known() {
+                let arr = SecretBase64Array([2u8; 32]);
+                let ser = serde_json::to_string(&arr).unwrap();
+                let de: SecretBase64Array<32> = serde_json::from_str(&ser).unwrap();
+                assert_eq!(arr, de);
+            }
+
+            #[test]
+            fn deserialize_wrong_length_fails() {
+                let base64_str = base64::encode_config([0u8; 12], STANDARD);
+                let received = serde_json::from_str::<SecretBase64Array<32>>(&format!(
+                    "\"{}\"",
+                    base64_str
+                ))
+                .unwrap_err();
+                assert!(received.is_data());
+            }
+
+            #[test]
+            fn from_str_wrong_length_fails() {
+                let base64_str = base64::encode_config([0u8; 12], STANDARD);
+                let received: Result<SecretBase64Array<32>, _> = base64_str.parse();
+                assert!(received.is_err());
+            }
+
+            #[test]
+            fn str_roundtrip() {
+                let arr = SecretBase64Array([7u8; 32]);
+                let received: SecretBase64Array<32> = arr.to_string().parse().unwrap();
+                assert_eq!(arr, received);
+            }
+
+            #[test]
+            fn constant_time_eq() {
+                let a = SecretBase64Array([1u8; 32]);
+                let mut other = [1u8; 32];
+                other[31] = 2;
+                let b = SecretBase64Array(other);
+                let c = SecretBase64Array([1u8; 32]);
+                assert_ne!(a, b);
+                assert_eq!(a, c);
+            }
+
+            #[test]
+            fn drop_zeroizes_buffer() {
+                // See secret_base64::drop_zeroizes_buffer for the caveat on this pattern. An
+                // inline array lives with the struct rather than behind a separate heap
+                // allocation, and dropping it in place through `ManuallyDrop` keeps that
+                // storage alive, so there's no dangling-pointer concern here.
+                let mut arr = core::mem::ManuallyDrop::new(SecretBase64Array([0xaa; 8]));
+                let ptr = arr.0.as_mut_ptr();
+                unsafe { core::mem::ManuallyDrop::drop(&mut arr) };
+                let after = unsafe { std::slice::from_raw_parts(ptr, 8) };
+                assert!(after.iter().all(|&b| b == 0));
             }
         }
     }